@@ -1,11 +1,13 @@
 // NOTE: this is inspired from the `bevy-orbit-controls` projects but
 //       with some modifications like Panning, and 2D support.
 //       Most of these modifications have been contributed upstream.
+use super::FrameCamera;
 use bevy::input::mouse::MouseMotion;
 use bevy::input::mouse::MouseScrollUnit::{Line, Pixel};
 use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
 use bevy::render::camera::Camera;
+use bevy::window::PrimaryWindow;
 
 const LINE_TO_PIXEL_RATIO: f32 = 0.1;
 
@@ -84,10 +86,30 @@ impl OrbitCameraPlugin {
             }
         }
     }
+
+    fn frame_system(
+        mut frame_events: EventReader<FrameCamera>,
+        windows: Query<&Window, With<PrimaryWindow>>,
+        mut query: Query<&mut OrbitCamera>,
+    ) {
+        if let Some(event) = frame_events.read().last() {
+            if let Ok(window) = windows.get_single() {
+                for mut camera in query.iter_mut() {
+                    camera.center.x = event.center.x;
+                    camera.center.y = event.center.y;
+                    if event.radius > 0.0 {
+                        camera.zoom = window.width().min(window.height()) / (event.radius * 2.0);
+                    }
+                }
+            }
+        }
+    }
 }
 impl Plugin for OrbitCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, Self::mouse_motion_system)
+        app.add_event::<FrameCamera>()
+            .add_systems(Update, Self::frame_system)
+            .add_systems(Update, Self::mouse_motion_system)
             .add_systems(Update, Self::zoom_system)
             .add_systems(Update, Self::update_transform_system);
     }
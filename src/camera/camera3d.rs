@@ -2,11 +2,12 @@
 //       with some modifications like Panning, and 2D support.
 //       Most of these modifications have been contributed upstream.
 
+use super::FrameCamera;
 use bevy::input::mouse::MouseMotion;
 use bevy::input::mouse::MouseScrollUnit::{Line, Pixel};
 use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
-use bevy::render::camera::Camera;
+use bevy::render::camera::{Camera, Projection};
 use std::ops::RangeInclusive;
 
 const LINE_TO_PIXEL_RATIO: f32 = 0.001;
@@ -110,10 +111,31 @@ impl OrbitCameraPlugin {
             }
         }
     }
+
+    fn frame_system(
+        mut frame_events: EventReader<FrameCamera>,
+        mut query: Query<(&mut OrbitCamera, &Projection)>,
+    ) {
+        if let Some(event) = frame_events.read().last() {
+            for (mut camera, projection) in query.iter_mut() {
+                let fov = match projection {
+                    Projection::Perspective(perspective) => perspective.fov,
+                    Projection::Orthographic(_) => std::f32::consts::FRAC_PI_4,
+                };
+
+                camera.center = event.center;
+                if event.radius > 0.0 {
+                    camera.distance = event.radius / (fov / 2.0).sin();
+                }
+            }
+        }
+    }
 }
 impl Plugin for OrbitCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, Self::mouse_motion_system)
+        app.add_event::<FrameCamera>()
+            .add_systems(Update, Self::frame_system)
+            .add_systems(Update, Self::mouse_motion_system)
             .add_systems(Update, Self::zoom_system)
             .add_systems(Update, Self::update_transform_system);
     }
@@ -3,6 +3,15 @@ pub use self::camera2d::{OrbitCamera, OrbitCameraPlugin};
 #[cfg(feature = "dim3")]
 pub use self::camera3d::{OrbitCamera, OrbitCameraPlugin};
 
+use bevy::prelude::*;
+
+/// Requests the orbit camera to move so that the given bounding sphere fills the view.
+#[derive(Event, Copy, Clone, Debug)]
+pub struct FrameCamera {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
 #[cfg(feature = "dim2")]
 mod camera2d;
 #[cfg(feature = "dim3")]
@@ -1,7 +1,9 @@
 use crate::parry::bounding_volume::Aabb;
 use bevy::prelude::*;
+use bevy_rapier::prelude::Sleeping;
 use clap::Parser;
 use nalgebra::point;
+use std::num::NonZeroUsize;
 
 #[derive(Parser, Debug, Copy, Clone, Resource)]
 #[command(author, version, about, long_about = None)]
@@ -22,6 +24,48 @@ pub struct CliArgs {
     pub distributed_physics: bool,
     #[arg(long, default_value_t = false)]
     pub lower_graphics: bool,
+    /// Number of solver iterations per step. Higher values stabilize stiff stacks
+    /// and joint chains, at the cost of a proportionally more expensive solver.
+    #[arg(long)]
+    pub num_solver_iterations: Option<NonZeroUsize>,
+    /// Number of internal PGS iterations run within each solver iteration.
+    #[arg(long)]
+    pub num_internal_pgs_iterations: Option<usize>,
+    /// Number of extra friction-only iterations run after the solver iterations.
+    #[arg(long)]
+    pub num_additional_friction_iterations: Option<usize>,
+    /// Initial simulation speed relative to realtime (e.g. `0.25` for bullet-time, `2.0` for
+    /// fast-forward). It can be changed later from the main menu.
+    #[arg(long, value_parser = parse_non_negative)]
+    pub time_scale: Option<f32>,
+    /// Number of substeps each physics step is split into. More substeps integrate fast
+    /// bodies more accurately without changing the simulation step rate.
+    #[arg(long)]
+    pub substeps: Option<NonZeroUsize>,
+    /// Maximum number of CCD substeps per step. Lowering it bounds the CCD cost with
+    /// many fast bodies, but can let them tunnel through thin objects again.
+    #[arg(long)]
+    pub max_ccd_substeps: Option<usize>,
+    /// Linear velocity threshold below which a body can fall asleep, in multiples of the
+    /// integration parameters’ length unit. Higher values let idle bodies sleep sooner, lower
+    /// values keep delicate setups awake.
+    #[arg(long)]
+    pub linear_sleep_threshold: Option<f32>,
+    /// Angular velocity threshold below which a body can fall asleep.
+    #[arg(long)]
+    pub angular_sleep_threshold: Option<f32>,
+    /// Time, in seconds, a body must stay below the sleep thresholds before falling asleep.
+    #[arg(long, value_parser = parse_non_negative)]
+    pub time_until_sleep: Option<f32>,
+    /// Linear velocities above this value are clamped after each step.
+    #[arg(long, value_parser = parse_non_negative)]
+    pub max_linvel: Option<f32>,
+    /// Angular velocities above this value are clamped after each step.
+    #[arg(long, value_parser = parse_non_negative)]
+    pub max_angvel: Option<f32>,
+    /// Dynamic bodies falling below this height are removed from the scene.
+    #[arg(long)]
+    pub kill_plane_y: Option<f32>,
 }
 
 impl CliArgs {
@@ -39,6 +83,16 @@ impl CliArgs {
         Aabb::new(mins, maxs)
     }
 
+    /// Replaces the sleep thresholds of `sleeping` by the ones set on the command line, if any.
+    pub fn override_sleep_thresholds(&self, sleeping: &mut Sleeping) {
+        if let Some(threshold) = self.linear_sleep_threshold {
+            sleeping.normalized_linear_threshold = threshold;
+        }
+        if let Some(threshold) = self.angular_sleep_threshold {
+            sleeping.angular_threshold = threshold;
+        }
+    }
+
     pub fn awareness_bounds(&self) -> Aabb {
         let mut result = self.simulation_bounds();
         let sim_extents = result.extents();
@@ -47,3 +101,93 @@ impl CliArgs {
         result
     }
 }
+
+fn parse_non_negative(arg: &str) -> Result<f32, String> {
+    let value: f32 = arg.parse().map_err(|e| format!("{e}"))?;
+    if value.is_finite() && value >= 0.0 {
+        Ok(value)
+    } else {
+        Err(format!(
+            "expected a finite non-negative number, got {value}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_rapier::rapier::prelude::*;
+
+    /// Steps a body moving slowly in zero gravity, and returns the number of steps it took
+    /// to fall asleep (or `max_steps` if it never did).
+    fn steps_until_sleep(sleeping: Sleeping, max_steps: usize) -> usize {
+        let mut bodies = RigidBodySet::new();
+        let mut colliders = ColliderSet::new();
+        let body = RigidBodyBuilder::dynamic()
+            .linvel(Vector::x() * 0.6)
+            .build();
+        let handle = bodies.insert(body);
+        colliders.insert_with_parent(ColliderBuilder::ball(0.5).build(), handle, &mut bodies);
+
+        let activation = bodies[handle].activation_mut();
+        activation.normalized_linear_threshold = sleeping.normalized_linear_threshold;
+        activation.angular_threshold = sleeping.angular_threshold;
+
+        let mut pipeline = PhysicsPipeline::new();
+        let mut islands = IslandManager::new();
+        let mut broad_phase = DefaultBroadPhase::new();
+        let mut narrow_phase = NarrowPhase::new();
+        let mut impulse_joints = ImpulseJointSet::new();
+        let mut multibody_joints = MultibodyJointSet::new();
+        let mut ccd_solver = CCDSolver::new();
+        let params = IntegrationParameters::default();
+
+        for step in 0..max_steps {
+            pipeline.step(
+                &Vector::zeros(),
+                &params,
+                &mut islands,
+                &mut broad_phase,
+                &mut narrow_phase,
+                &mut bodies,
+                &mut colliders,
+                &mut impulse_joints,
+                &mut multibody_joints,
+                &mut ccd_solver,
+                None,
+                &(),
+                &(),
+            );
+
+            if bodies[handle].is_sleeping() {
+                return step;
+            }
+        }
+
+        max_steps
+    }
+
+    #[test]
+    fn cli_sleep_thresholds_let_bodies_sleep_sooner() {
+        let cli = CliArgs::parse_from(["steadyum", "--linear-sleep-threshold", "1.0"]);
+        let mut sleeping = Sleeping::default();
+        cli.override_sleep_thresholds(&mut sleeping);
+        assert_eq!(sleeping.normalized_linear_threshold, 1.0);
+
+        let max_steps = 1000;
+        let default_steps = steps_until_sleep(Sleeping::default(), max_steps);
+        let cli_steps = steps_until_sleep(sleeping, max_steps);
+        assert!(cli_steps < default_steps);
+    }
+
+    #[test]
+    fn missing_cli_sleep_thresholds_keep_the_defaults() {
+        let cli = CliArgs::parse_from(["steadyum"]);
+        let mut sleeping = Sleeping::default();
+        cli.override_sleep_thresholds(&mut sleeping);
+        assert_eq!(
+            sleeping.normalized_linear_threshold,
+            Sleeping::default().normalized_linear_threshold
+        );
+    }
+}
@@ -127,6 +127,11 @@ impl InsertionState {
         };
 
         let mut transform = self.transform();
+
+        if self.tool == SelectedTool::AddHeightfield {
+            return Self::heightfield_operation(transform);
+        }
+
         let mut collider = self.preview_shape.clone().unwrap();
 
         // For the capsule, we need to make a special-case so we
@@ -152,31 +157,6 @@ impl InsertionState {
                     transform.scale.z /= diameter;
                 }
             }
-        } else if self.tool == SelectedTool::AddHeightfield {
-            let perlin = Perlin::default();
-
-            #[cfg(feature = "dim2")]
-            {
-                let num_rows = 100;
-                let heights = (0..num_rows)
-                    .map(|i| perlin.get([i as f64 / 100.0, 0.0]) as f32)
-                    .collect();
-                collider = Collider::heightfield(heights, Vec2::ONE);
-            }
-
-            #[cfg(feature = "dim3")]
-            {
-                let (num_rows, num_cols) = (100, 100);
-                let heights = DMatrix::from_fn(num_rows, num_cols, |i, j| {
-                    perlin.get([i as f64 / 100.0, j as f64 / 100.0]) as f32
-                });
-                collider = Collider::heightfield(
-                    heights.data.as_vec().clone(),
-                    num_rows,
-                    num_cols,
-                    Vec3::ONE,
-                );
-            }
         }
 
         Operation::AddCollider(
@@ -189,6 +169,34 @@ impl InsertionState {
         )
     }
 
+    fn heightfield_operation(transform: Transform) -> Operation {
+        let perlin = Perlin::default();
+
+        #[cfg(feature = "dim2")]
+        let (heights, scale) = {
+            let num_rows = 100;
+            let heights = (0..num_rows)
+                .map(|i| perlin.get([i as f64 / 100.0, 0.0]) as f32)
+                .collect();
+            (heights, transform.scale.truncate())
+        };
+
+        #[cfg(feature = "dim3")]
+        let (heights, scale) = {
+            let (num_rows, num_cols) = (100, 100);
+            let heights = DMatrix::from_fn(num_rows, num_cols, |i, j| {
+                perlin.get([i as f64 / 100.0, j as f64 / 100.0]) as f32
+            });
+            (heights, transform.scale)
+        };
+
+        Operation::AddHeightfield {
+            heights,
+            scale,
+            transform,
+        }
+    }
+
     pub fn set_tool(&mut self, tool: SelectedTool) {
         if tool != self.tool {
             self.tool = tool;
@@ -0,0 +1,64 @@
+use crate::cli::CliArgs;
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+/// Plugin responsible for removing the dynamic bodies that fell below the kill plane.
+pub struct KillPlanePlugin;
+
+impl Plugin for KillPlanePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            despawn_fallen_bodies.after(PhysicsSet::Writeback),
+        );
+    }
+}
+
+fn despawn_fallen_bodies(
+    mut commands: Commands,
+    cli: Res<CliArgs>,
+    bodies: Query<(Entity, &RigidBody, &GlobalTransform)>,
+) {
+    if let Some(kill_plane_y) = cli.kill_plane_y {
+        for (entity, body, transform) in bodies.iter() {
+            if *body == RigidBody::Dynamic && transform.translation().y < kill_plane_y {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use clap::Parser;
+
+    #[test]
+    fn dynamic_bodies_below_the_kill_plane_are_despawned() {
+        let mut world = World::new();
+        world.insert_resource(CliArgs::parse_from(["steadyum", "--kill-plane-y", "-10"]));
+
+        let fallen = world
+            .spawn((
+                RigidBody::Dynamic,
+                GlobalTransform::from_translation(Vec3::Y * -20.0),
+            ))
+            .id();
+        let above = world
+            .spawn((RigidBody::Dynamic, GlobalTransform::IDENTITY))
+            .id();
+        let fixed = world
+            .spawn((
+                RigidBody::Fixed,
+                GlobalTransform::from_translation(Vec3::Y * -20.0),
+            ))
+            .id();
+
+        world.run_system_once(despawn_fallen_bodies);
+
+        assert!(world.get_entity(fallen).is_none());
+        assert!(world.get_entity(above).is_some());
+        assert!(world.get_entity(fixed).is_some());
+    }
+}
@@ -21,6 +21,7 @@ use winit::window::WindowId;
 // use bevy_infinite_grid::GridShadowCamera;
 use bevy_rapier::prelude::Real;
 use bevy_rapier::prelude::*;
+use bevy_rapier::rapier::dynamics::IntegrationParameters;
 use clap::Parser;
 use winit::window::Icon;
 
@@ -38,8 +39,11 @@ mod builtin_scenes;
 mod cli;
 mod control;
 mod drag;
+mod kill_plane;
 mod layers;
+mod one_way_platform;
 mod projectile;
+mod velocity_clamp;
 
 #[derive(Component)]
 pub struct MainCamera;
@@ -84,7 +88,7 @@ fn main() {
         // .add_plugins(bevy_stl::StlPlugin)
         .add_plugins(bevy_obj::ObjPlugin)
         .add_plugins(selection::SelectionPlugins)
-        .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+        .add_plugins(RapierPhysicsPlugin::<one_way_platform::OneWayPlatformHooks>::default())
         .add_plugins(RapierDebugRenderPlugin::default().disabled())
         .add_plugins(render::RapierRenderPlugin)
         .add_plugins(ui::RapierUiPlugin)
@@ -96,6 +100,9 @@ fn main() {
         .add_plugins(drag::DragPlugin)
         .add_plugins(projectile::ProjectilePlugin)
         .add_plugins(control::ControlPlugin)
+        .add_plugins(kill_plane::KillPlanePlugin)
+        .add_plugins(one_way_platform::OneWayPlatformPlugin)
+        .add_plugins(velocity_clamp::VelocityClampPlugin)
         .add_plugins(OrbitCameraPlugin)
         // .add_stage_after(
         //     PhysicsStages::Writeback,
@@ -105,7 +112,17 @@ fn main() {
         // .add_startup_system(set_window_icon)
         .add_systems(Startup, init_profiling_and_gravity)
         .add_systems(Startup, setup_graphics)
-        .add_systems(Startup, setup_physics);
+        .add_systems(Startup, setup_physics)
+        .add_systems(
+            PostUpdate,
+            apply_sleep_thresholds.before(PhysicsSet::SyncBackend),
+        )
+        .add_systems(
+            PostUpdate,
+            apply_time_until_sleep
+                .after(PhysicsSet::SyncBackend)
+                .before(PhysicsSet::StepSimulation),
+        );
 
     app.add_plugins(bevy_polyline::PolylinePlugin);
 
@@ -280,15 +297,76 @@ pub fn look_at(camera: &mut OrbitCamera, eye: Vec3, at: Vec3) {
 pub fn setup_physics(
     cli: Res<CliArgs>,
     mut config: ResMut<RapierConfiguration>,
+    mut physics: ResMut<RapierContext>,
     mut debug_render_context: ResMut<DebugRenderContext>,
 ) {
     config.physics_pipeline_active = false;
     config.query_pipeline_active = !cli.distributed_physics;
+
+    if let Some(time_scale) = cli.time_scale {
+        match &mut config.timestep_mode {
+            TimestepMode::Variable { time_scale: s, .. }
+            | TimestepMode::Interpolated { time_scale: s, .. } => *s = time_scale,
+            TimestepMode::Fixed { .. } => warn!("The fixed timestep mode can’t be time-scaled."),
+        }
+    }
+
+    if let Some(substeps) = cli.substeps {
+        match &mut config.timestep_mode {
+            TimestepMode::Fixed { substeps: s, .. }
+            | TimestepMode::Variable { substeps: s, .. }
+            | TimestepMode::Interpolated { substeps: s, .. } => *s = substeps.get(),
+        }
+    }
+
+    apply_cli_params(&cli, &mut physics.integration_parameters);
+
     debug_render_context.pipeline.style.rigid_body_axes_length = 0.5;
     // debug_render_context.always_on_top = cfg!(feature = "dim2");
     debug_render_context.enabled = false;
 }
 
+fn apply_cli_params(cli: &CliArgs, params: &mut IntegrationParameters) {
+    if let Some(num_solver_iterations) = cli.num_solver_iterations {
+        params.num_solver_iterations = num_solver_iterations;
+    }
+    if let Some(num_internal_pgs_iterations) = cli.num_internal_pgs_iterations {
+        params.num_internal_pgs_iterations = num_internal_pgs_iterations;
+    }
+    if let Some(num_additional_friction_iterations) = cli.num_additional_friction_iterations {
+        params.num_additional_friction_iterations = num_additional_friction_iterations;
+    }
+    if let Some(max_ccd_substeps) = cli.max_ccd_substeps {
+        params.max_ccd_substeps = max_ccd_substeps;
+    }
+}
+
+fn apply_sleep_thresholds(cli: Res<CliArgs>, mut sleeping: Query<&mut Sleeping, Added<Sleeping>>) {
+    for mut sleeping in sleeping.iter_mut() {
+        // Don’t re-enable sleeping on bodies that were explicitly prevented from sleeping.
+        if sleeping.normalized_linear_threshold < 0.0 || sleeping.angular_threshold < 0.0 {
+            continue;
+        }
+
+        cli.override_sleep_thresholds(&mut sleeping);
+    }
+}
+
+fn apply_time_until_sleep(
+    cli: Res<CliArgs>,
+    mut physics: ResMut<RapierContext>,
+    new_bodies: Query<&RapierRigidBodyHandle, Added<RapierRigidBodyHandle>>,
+) {
+    // bevy_rapier’s `Sleeping` component doesn’t expose this, so set it on the rapier body.
+    if let Some(time_until_sleep) = cli.time_until_sleep {
+        for handle in new_bodies.iter() {
+            if let Some(body) = physics.bodies.get_mut(handle.0) {
+                body.activation_mut().time_until_sleep = time_until_sleep;
+            }
+        }
+    }
+}
+
 // TODO: move this elsewhere
 #[cfg(feature = "voxels")]
 fn handle_fractures(
@@ -309,3 +387,35 @@ fn handle_fractures(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cli_solver_iterations_are_applied() {
+        let cli = CliArgs::parse_from(["steadyum", "--num-solver-iterations", "8"]);
+        let mut params = IntegrationParameters::default();
+        apply_cli_params(&cli, &mut params);
+        assert_eq!(params.num_solver_iterations.get(), 8);
+    }
+
+    #[test]
+    fn cli_max_ccd_substeps_are_applied() {
+        let cli = CliArgs::parse_from(["steadyum", "--max-ccd-substeps", "3"]);
+        let mut params = IntegrationParameters::default();
+        apply_cli_params(&cli, &mut params);
+        assert_eq!(params.max_ccd_substeps, 3);
+    }
+
+    #[test]
+    fn missing_cli_params_keep_the_defaults() {
+        let cli = CliArgs::parse_from(["steadyum"]);
+        let mut params = IntegrationParameters::default();
+        apply_cli_params(&cli, &mut params);
+
+        let defaults = IntegrationParameters::default();
+        assert_eq!(params.num_solver_iterations, defaults.num_solver_iterations);
+        assert_eq!(params.max_ccd_substeps, defaults.max_ccd_substeps);
+    }
+}
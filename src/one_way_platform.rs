@@ -0,0 +1,114 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+use bevy_rapier::rapier::math::{Real, Rotation, Vector};
+
+/// Maximum angle between a contact normal and the platform’s up direction for the contact to be
+/// kept.
+const ALLOWED_ANGLE: Real = 0.1;
+
+/// Marks a collider as a one-way platform: bodies can go through it from below, but land on it
+/// from above (along its local +Y axis).
+#[derive(Copy, Clone, Debug, Default, Component)]
+pub struct OneWayPlatform;
+
+/// Plugin responsible for enabling the contact modification hooks of one-way platforms.
+pub struct OneWayPlatformPlugin;
+
+impl Plugin for OneWayPlatformPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (enable_one_way_hooks, disable_one_way_hooks).before(PhysicsSet::SyncBackend),
+        );
+    }
+}
+
+fn enable_one_way_hooks(mut commands: Commands, platforms: Query<Entity, Added<OneWayPlatform>>) {
+    for entity in platforms.iter() {
+        commands
+            .entity(entity)
+            .insert(ActiveHooks::MODIFY_SOLVER_CONTACTS);
+    }
+}
+
+fn disable_one_way_hooks(
+    mut commands: Commands,
+    mut removed: RemovedComponents<OneWayPlatform>,
+    existing_entities: Query<Entity>,
+) {
+    for entity in removed.read() {
+        if existing_entities.get(entity).is_ok() {
+            commands.entity(entity).remove::<ActiveHooks>();
+        }
+    }
+}
+
+/// Physics hooks dropping the contacts of one-way platforms whose normal opposes the platform’s
+/// up direction.
+#[derive(SystemParam)]
+pub struct OneWayPlatformHooks<'w, 's> {
+    platforms: Query<'w, 's, (), With<OneWayPlatform>>,
+}
+
+impl BevyPhysicsHooks for OneWayPlatformHooks<'_, '_> {
+    fn modify_solver_contacts(&self, mut context: ContactModificationContextView) {
+        let platform1 = self.platforms.contains(context.collider1());
+        let platform2 = self.platforms.contains(context.collider2());
+
+        let colliders = context.raw.colliders;
+        let rot1 = colliders[context.raw.collider1].position().rotation;
+        let rot2 = colliders[context.raw.collider2].position().rotation;
+
+        let allowed_local_n1 = match allowed_local_normal(platform1, platform2, &rot1, &rot2) {
+            Some(normal) => normal,
+            None => return,
+        };
+
+        context
+            .raw
+            .update_as_oneway_platform(&allowed_local_n1, ALLOWED_ANGLE);
+    }
+}
+
+/// The contact normal allowed by a one-way platform, in the local frame of the first collider.
+///
+/// Returns `None` if neither collider is a platform.
+fn allowed_local_normal(
+    platform1: bool,
+    platform2: bool,
+    rot1: &Rotation<Real>,
+    rot2: &Rotation<Real>,
+) -> Option<Vector<Real>> {
+    if platform1 {
+        Some(Vector::y())
+    } else if platform2 {
+        // Contacts pushing the first collider down onto the platform point against its up axis.
+        Some(-(rot1.inverse() * (rot2 * Vector::y())))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_normal_points_away_from_the_platform() {
+        let identity = Rotation::identity();
+
+        assert_eq!(
+            allowed_local_normal(true, false, &identity, &identity),
+            Some(Vector::y())
+        );
+        assert_eq!(
+            allowed_local_normal(false, true, &identity, &identity),
+            Some(-Vector::y())
+        );
+        assert_eq!(
+            allowed_local_normal(false, false, &identity, &identity),
+            None
+        );
+    }
+}
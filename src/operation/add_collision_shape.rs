@@ -2,6 +2,9 @@ use crate::operation::{Operation, Operations};
 use crate::styling::ColorGenerator;
 use crate::utils::ColliderRenderBundle;
 use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+const SCALE_SUBDIVISIONS: u32 = 10;
 
 pub fn add_collision_shape(
     mut commands: Commands,
@@ -10,11 +13,128 @@ pub fn add_collision_shape(
 ) {
     for op in operations.iter() {
         if let Operation::AddCollider(collider, rigid_body, transform) = op {
+            let mut collider = collider.clone();
+            let mut transform = *transform;
+            bake_scale(&mut collider.collider, &mut transform);
+
             commands
-                .spawn(collider.clone())
+                .spawn(collider)
                 .insert(rigid_body.clone())
-                .insert(TransformBundle::from_transform(*transform))
+                .insert(TransformBundle::from_transform(transform))
                 .insert(ColliderRenderBundle::new(&mut colors));
         }
     }
 }
+
+/// Applies the transform’s scale to the collider’s shape, and resets the scale to one.
+///
+/// Only scales that keep the shape’s type are baked (e.g. any scale on a cuboid, but only
+/// uniform scales on a ball). Other scales are left on the transform, which the renderer
+/// and Rapier already know how to approximate.
+fn bake_scale(collider: &mut Collider, transform: &mut Transform) {
+    #[cfg(feature = "dim2")]
+    let scale = transform.scale.truncate();
+    #[cfg(feature = "dim3")]
+    let scale = transform.scale;
+
+    if scale == Vect::ONE {
+        return;
+    }
+
+    if !supports_scale_exactly(collider, scale) {
+        warn!(
+            "Scale {} can’t be represented exactly by a {:?}, keeping it on the transform.",
+            scale,
+            collider.raw.shape_type()
+        );
+        return;
+    }
+
+    collider.set_scale(scale, SCALE_SUBDIVISIONS);
+
+    if collider.scale() != scale {
+        warn!(
+            "Scale {} can’t be applied to a {:?}, keeping it on the transform.",
+            scale,
+            collider.raw.shape_type()
+        );
+        collider.set_scale(Vect::ONE, SCALE_SUBDIVISIONS);
+        return;
+    }
+
+    *collider = Collider::from(collider.raw.clone());
+    transform.scale = Vec3::ONE;
+}
+
+fn supports_scale_exactly(collider: &Collider, scale: Vect) -> bool {
+    let uniform = scale == Vect::splat(scale.x);
+
+    match collider.as_unscaled_typed_shape() {
+        ColliderView::Ball(_) | ColliderView::Capsule(_) => uniform,
+        #[cfg(feature = "dim3")]
+        ColliderView::Cylinder(_) | ColliderView::Cone(_) => scale.x == scale.z,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn bake_scale_scales_cuboid_half_extents() {
+        let mut collider = Collider::cuboid(0.5, 0.5, 0.5);
+        let mut transform = Transform::from_scale(Vec3::new(2.0, 1.0, 1.0));
+        bake_scale(&mut collider, &mut transform);
+
+        let cuboid = collider
+            .as_cuboid()
+            .expect("the shape should still be a cuboid");
+        assert_eq!(cuboid.half_extents(), Vec3::new(1.0, 0.5, 0.5));
+        assert_eq!(transform.scale, Vec3::ONE);
+    }
+
+    #[test]
+    #[cfg(feature = "dim2")]
+    fn bake_scale_scales_cuboid_half_extents() {
+        let mut collider = Collider::cuboid(0.5, 0.5);
+        let mut transform = Transform::from_scale(Vec3::new(2.0, 1.0, 1.0));
+        bake_scale(&mut collider, &mut transform);
+
+        let cuboid = collider
+            .as_cuboid()
+            .expect("the shape should still be a cuboid");
+        assert_eq!(cuboid.half_extents(), Vec2::new(1.0, 0.5));
+        assert_eq!(transform.scale, Vec3::ONE);
+    }
+
+    #[test]
+    fn bake_scale_keeps_non_uniform_scale_on_balls_and_capsules() {
+        let scale = Vec3::new(2.0, 1.0, 1.0);
+
+        for shape in [Collider::ball(0.5), Collider::capsule_y(0.25, 0.25)] {
+            let shape_type = shape.raw.shape_type();
+            let mut collider = shape;
+            let mut transform = Transform::from_scale(scale);
+            bake_scale(&mut collider, &mut transform);
+
+            assert_eq!(collider.raw.shape_type(), shape_type);
+            assert_eq!(collider.scale(), Vect::ONE);
+            assert_eq!(transform.scale, scale);
+        }
+    }
+
+    #[test]
+    fn bake_scale_bakes_uniform_scale_on_balls() {
+        let mut collider = Collider::ball(0.5);
+        let mut transform = Transform::from_scale(Vec3::splat(2.0));
+        bake_scale(&mut collider, &mut transform);
+
+        let ball = collider
+            .as_ball()
+            .expect("the shape should still be a ball");
+        assert_eq!(ball.radius(), 1.0);
+        assert_eq!(transform.scale, Vec3::ONE);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::operation::{Operation, Operations};
+use crate::styling::ColorGenerator;
+use crate::utils::{ColliderBundle, ColliderRenderBundle, RigidBodyBundle};
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+#[cfg(feature = "dim3")]
+use na::DMatrix;
+
+pub fn add_heightfield(
+    mut commands: Commands,
+    operations: Res<Operations>,
+    mut colors: ResMut<ColorGenerator>,
+) {
+    for op in operations.iter() {
+        if let Operation::AddHeightfield {
+            heights,
+            scale,
+            transform,
+        } = op
+        {
+            commands
+                .spawn(ColliderBundle::new(heightfield_collider(heights, *scale)))
+                .insert(RigidBodyBundle::fixed())
+                .insert(TransformBundle::from_transform(Transform {
+                    scale: Vec3::ONE,
+                    ..*transform
+                }))
+                .insert(ColliderRenderBundle::new(&mut colors));
+        }
+    }
+}
+
+#[cfg(feature = "dim2")]
+fn heightfield_collider(heights: &[f32], scale: Vect) -> Collider {
+    Collider::heightfield(heights.to_vec(), scale)
+}
+
+#[cfg(feature = "dim3")]
+fn heightfield_collider(heights: &DMatrix<f32>, scale: Vect) -> Collider {
+    Collider::heightfield(
+        heights.data.as_vec().clone(),
+        heights.nrows(),
+        heights.ncols(),
+        scale,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heightfield_has_a_finite_bounding_sphere() {
+        #[cfg(feature = "dim2")]
+        let heights = vec![0.0, 1.0, 0.5, 2.0];
+        #[cfg(feature = "dim3")]
+        let heights = DMatrix::from_fn(4, 4, |i, j| (i + j) as f32 * 0.5);
+
+        let collider = heightfield_collider(&heights, Vect::splat(10.0));
+        let sphere = collider.raw.compute_local_bounding_sphere();
+        assert!(sphere.radius().is_finite());
+        assert!(sphere.center().coords.iter().all(|x| x.is_finite()));
+    }
+}
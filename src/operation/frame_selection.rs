@@ -0,0 +1,64 @@
+use crate::camera::FrameCamera;
+use crate::operation::{Operation, Operations};
+use crate::parry::bounding_volume::{Aabb, BoundingVolume};
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+pub fn frame_selection(
+    operations: Res<Operations>,
+    colliders: Query<(Entity, &Collider, &GlobalTransform)>,
+    mut frame_events: EventWriter<FrameCamera>,
+) {
+    for op in operations.iter() {
+        if let Operation::FrameSelection { entities } = op {
+            // An empty selection frames the whole scene.
+            let aabbs = colliders
+                .iter()
+                .filter(|(entity, ..)| entities.is_empty() || entities.contains(entity))
+                .map(|(_, collider, transform)| {
+                    let pos = bevy_rapier::utils::transform_to_iso(&transform.compute_transform());
+                    collider.raw.compute_aabb(&pos)
+                });
+
+            if let Some(event) = frame_target(aabbs) {
+                frame_events.send(event);
+            }
+        }
+    }
+}
+
+/// The camera target framing all the given AABBs, or `None` if there are none.
+fn frame_target(aabbs: impl IntoIterator<Item = Aabb>) -> Option<FrameCamera> {
+    let aabb = aabbs.into_iter().reduce(|a, b| a.merged(&b))?;
+    let center = aabb.center();
+
+    Some(FrameCamera {
+        #[cfg(feature = "dim2")]
+        center: Vec3::new(center.x, center.y, 0.0),
+        #[cfg(feature = "dim3")]
+        center: Vec3::new(center.x, center.y, center.z),
+        radius: aabb.half_extents().norm(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parry::math::{Point, Vector};
+
+    #[test]
+    fn frame_target_of_two_cubes_is_centered_on_their_midpoint() {
+        let half_extents = Vector::repeat(0.5);
+        let cube1 = Aabb::from_half_extents(Point::origin(), half_extents);
+        let cube2 = Aabb::from_half_extents(Point::from(Vector::x() * 4.0), half_extents);
+
+        let target = frame_target([cube1, cube2]).unwrap();
+        assert_eq!(target.center, Vec3::new(2.0, 0.0, 0.0));
+        assert!(target.radius > 2.0);
+    }
+
+    #[test]
+    fn frame_target_of_nothing_is_none() {
+        assert!(frame_target(std::iter::empty()).is_none());
+    }
+}
@@ -1,36 +1,216 @@
-use crate::operation::{Operation, Operations};
+use crate::operation::{ImportError, Operation, Operations};
+use crate::parry::shape::{SharedShape, TriMeshFlags};
 use crate::utils::{ColliderRenderBundle, RigidBodyBundle};
+use bevy::asset::LoadState;
 use bevy::prelude::*;
 use bevy_rapier::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// The axis pointing upward in an imported file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum UpAxis {
+    #[default]
+    Y,
+    Z,
+}
+
+/// Options applied to a mesh imported through [`Operation::ImportMesh`].
+#[derive(Copy, Clone, Debug, Component)]
+pub struct MeshImportOptions {
+    /// Flags set on the collider if the mesh is imported as a triangle mesh
+    /// (e.g. `FIX_INTERNAL_EDGES` against ghost collisions on internal edges).
+    ///
+    /// Note that `ORIENTED` only enables inside/outside tests based on pseudo-normals, it
+    /// doesn’t cull contacts: one-way platforms still need a contact modification hook.
+    pub trimesh_flags: TriMeshFlags,
+    /// The up axis of the file, converted to Steadyum’s Y-up convention on import.
+    pub up_axis: UpAxis,
+    /// Uniform scale converting the file’s units to meters (e.g. `0.01` for centimeters).
+    pub scale: f32,
+}
+
+impl Default for MeshImportOptions {
+    fn default() -> Self {
+        Self {
+            trimesh_flags: TriMeshFlags::empty(),
+            up_axis: UpAxis::default(),
+            scale: 1.0,
+        }
+    }
+}
+
+impl MeshImportOptions {
+    /// Flags suited for level floors: bodies slide across the triangle edges without bumping
+    /// into them.
+    pub const LEVEL_FLOOR_FLAGS: TriMeshFlags = TriMeshFlags::FIX_INTERNAL_EDGES
+        .union(TriMeshFlags::MERGE_DUPLICATE_VERTICES)
+        .union(TriMeshFlags::DELETE_DEGENERATE_TRIANGLES);
+
+    /// The transform converting the imported mesh to Steadyum’s coordinate system and units.
+    pub fn conversion_transform(&self) -> Transform {
+        let rotation = match self.up_axis {
+            UpAxis::Y => Quat::IDENTITY,
+            // Rotates +Z onto +Y, keeping the frame right-handed.
+            UpAxis::Z => Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2),
+        };
+
+        Transform {
+            rotation,
+            scale: Vec3::splat(self.scale),
+            ..Default::default()
+        }
+    }
+}
+
+/// A mesh being loaded, which gets its collider once the mesh asset is available.
+#[derive(Clone, Component)]
+pub struct PendingMeshImport {
+    path: PathBuf,
+    shape: ComputedColliderShape,
+}
 
 pub fn import_mesh(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     operations: Res<Operations>,
+    mut errors: EventWriter<ImportError>,
 ) {
     let operations = &*operations;
     for op in operations.iter() {
-        if let Operation::ImportMesh(path, shape) = op {
-            // let handle: Handle<Mesh> = asset_server.load(path.as_path());
-            // commands
-            //     .spawn(AsyncCollider(shape.clone()))
-            //     .insert(handle)
-            //     .insert(TransformBundle::default())
-            //     .insert(RigidBodyBundle::fixed())
-            //     .insert(ColliderRenderBundle::default());
+        if let Operation::ImportMesh(path, shape, options) = op {
+            if let Err(error) = check_import_path(path) {
+                errors.send(error);
+                continue;
+            }
+
+            let handle: Handle<Mesh> = asset_server.load(path.as_path());
+            commands
+                .spawn(PendingMeshImport {
+                    path: path.clone(),
+                    shape: shape.clone(),
+                })
+                .insert(handle)
+                .insert(*options)
+                .insert(TransformBundle::from_transform(
+                    options.conversion_transform(),
+                ))
+                .insert(RigidBodyBundle::fixed())
+                .insert(ColliderRenderBundle::default());
         }
     }
 }
 
-pub fn set_trimesh_flags(_changed_shapes: Query<&mut Collider, Changed<Collider>>) {
-    // for mut shape in changed_shapes.iter_mut() {
-    //     if shape.as_trimesh().is_some() {
-    //         // Check immutably first to avoid triggering bevy’s change detection.
-    //         if let Some(mut trimesh) = shape.as_trimesh_mut() {
-    //             if let Err(topo_err) = trimesh.set_flags(TriMeshFlags::all()) {
-    //                 error!("topology computation error {}", topo_err);
-    //             }
-    //         }
-    //     }
-    // }
+/// Checks that `path` can be imported before starting to load it.
+fn check_import_path(path: &Path) -> Result<(), ImportError> {
+    if path.exists() {
+        Ok(())
+    } else {
+        Err(ImportError {
+            path: path.to_path_buf(),
+            reason: "file not found".to_string(),
+        })
+    }
+}
+
+pub fn finish_mesh_imports(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    meshes: Res<Assets<Mesh>>,
+    pending: Query<(
+        Entity,
+        &PendingMeshImport,
+        &MeshImportOptions,
+        &Handle<Mesh>,
+    )>,
+    mut errors: EventWriter<ImportError>,
+) {
+    for (entity, import, options, handle) in pending.iter() {
+        let reason = if let Some(mesh) = meshes.get(handle) {
+            if let Some(mut collider) = Collider::from_bevy_mesh(mesh, &import.shape) {
+                // Set the flags before the collider is rendered, so merged or deleted
+                // vertices are reflected by its render mesh.
+                if let Some(flagged) = with_trimesh_flags(&collider, options.trimesh_flags) {
+                    collider = flagged;
+                }
+
+                commands
+                    .entity(entity)
+                    .insert(collider)
+                    .remove::<PendingMeshImport>();
+                continue;
+            }
+
+            "failed to compute a collider from the mesh"
+        } else if matches!(asset_server.get_load_state(handle), Some(LoadState::Failed)) {
+            "failed to load the mesh"
+        } else {
+            // Still loading.
+            continue;
+        };
+
+        errors.send(ImportError {
+            path: import.path.clone(),
+            reason: reason.to_string(),
+        });
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn set_trimesh_flags(
+    mut changed_shapes: Query<(&mut Collider, &MeshImportOptions), Changed<Collider>>,
+) {
+    for (mut shape, options) in changed_shapes.iter_mut() {
+        // Check immutably first to avoid triggering bevy’s change detection.
+        if let Some(flagged) = with_trimesh_flags(&shape, options.trimesh_flags) {
+            *shape = flagged;
+        }
+    }
+}
+
+/// Returns a copy of `collider` with the given flags, if it is a triangle mesh with other flags.
+fn with_trimesh_flags(collider: &Collider, flags: TriMeshFlags) -> Option<Collider> {
+    match collider.as_unscaled_typed_shape() {
+        ColliderView::TriMesh(trimesh) if trimesh.raw.flags() != flags => {
+            let mut trimesh = trimesh.raw.clone();
+            if let Err(topo_err) = trimesh.set_flags(flags) {
+                error!("topology computation error {}", topo_err);
+            }
+            Some(Collider::from(SharedShape::new(trimesh)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversion_transform_maps_z_up_to_y_up() {
+        let options = MeshImportOptions {
+            up_axis: UpAxis::Z,
+            scale: 0.01,
+            ..Default::default()
+        };
+        let transform = options.conversion_transform();
+
+        assert!((transform.rotation * Vec3::Z).abs_diff_eq(Vec3::Y, 1.0e-6));
+        assert_eq!(transform.scale, Vec3::splat(0.01));
+    }
+
+    #[test]
+    fn importing_a_missing_file_is_an_error() {
+        let path = Path::new("this/file/does/not/exist.obj");
+        let error = check_import_path(path).unwrap_err();
+        assert_eq!(error.path, path);
+        assert_eq!(error.reason, "file not found");
+    }
+
+    #[test]
+    fn default_conversion_transform_is_identity() {
+        assert_eq!(
+            MeshImportOptions::default().conversion_transform(),
+            Transform::IDENTITY
+        );
+    }
 }
@@ -1,23 +1,38 @@
-pub use self::operations::{Operation, Operations};
+pub use self::operations::{ImportError, Operation, Operations};
 pub use self::plugin::RapierOperationsPlugin;
 
 pub use self::add_collision_shape::add_collision_shape;
+pub use self::add_heightfield::add_heightfield;
 pub use self::add_intersection::{add_intersection, update_intersection, PersistentIntersection};
 pub use self::add_plane::add_plane;
 pub use self::clear_scene::clear_scene;
+pub use self::frame_selection::frame_selection;
 
 #[cfg(feature = "dim3")]
-pub use self::import_mesh::{import_mesh, set_trimesh_flags};
+pub use self::import_mesh::{
+    finish_mesh_imports, import_mesh, set_trimesh_flags, MeshImportOptions, PendingMeshImport,
+    UpAxis,
+};
 pub use self::import_scene::import_scene;
+#[cfg(feature = "dim3")]
+pub use self::recompute_collider_shape::recompute_collider_shape;
+pub use self::scene_stats::{scene_stats, SceneStats};
+pub use self::set_body_type::set_body_type;
 
 mod operations;
 mod plugin;
 
 mod add_collision_shape;
+mod add_heightfield;
 mod add_intersection;
 mod add_plane;
 mod clear_scene;
+mod frame_selection;
 
 #[cfg(feature = "dim3")]
 mod import_mesh;
 mod import_scene;
+#[cfg(feature = "dim3")]
+mod recompute_collider_shape;
+mod scene_stats;
+mod set_body_type;
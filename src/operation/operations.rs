@@ -1,20 +1,62 @@
 use bevy::prelude::*;
 
+#[cfg(feature = "dim3")]
+use crate::operation::MeshImportOptions;
 use crate::utils::{ColliderBundle, RigidBodyBundle};
+use bevy_rapier::dynamics::RigidBody;
 #[cfg(feature = "dim3")]
 use bevy_rapier::geometry::ComputedColliderShape;
+use bevy_rapier::math::Vect;
 use bevy_rapier::plugin::RapierContext;
+#[cfg(feature = "dim3")]
+use na::DMatrix;
 use std::path::PathBuf;
 
 pub enum Operation {
     #[cfg(feature = "dim3")]
-    ImportMesh(PathBuf, ComputedColliderShape),
+    ImportMesh(PathBuf, ComputedColliderShape, MeshImportOptions),
     AddPlane, // { start: Point<f32>, stop: Point<f32> },
     AddCollider(ColliderBundle, RigidBodyBundle, Transform),
+    /// Adds a fixed heightfield scaled by `scale`. The scale of `transform` is ignored.
+    #[cfg(feature = "dim2")]
+    AddHeightfield {
+        heights: Vec<f32>,
+        scale: Vect,
+        transform: Transform,
+    },
+    /// Adds a fixed heightfield scaled by `scale`. The scale of `transform` is ignored.
+    #[cfg(feature = "dim3")]
+    AddHeightfield {
+        heights: DMatrix<f32>,
+        scale: Vect,
+        transform: Transform,
+    },
     AddIntersection,
     ExportScene(PathBuf),
     ImportScene(RapierContext),
     ClearScene,
+    SceneStats,
+    SetBodyType {
+        entity: Entity,
+        body_type: RigidBody,
+    },
+    /// Moves the camera to frame the given entities, or the whole scene if there are none.
+    FrameSelection {
+        entities: Vec<Entity>,
+    },
+    /// Rebuilds the collider of an imported mesh with a different shape computation strategy.
+    #[cfg(feature = "dim3")]
+    RecomputeColliderShape {
+        entity: Entity,
+        shape: ComputedColliderShape,
+    },
+}
+
+/// Sent when a file couldn’t be imported into the scene.
+#[derive(Event, Clone, Debug)]
+pub struct ImportError {
+    pub path: PathBuf,
+    pub reason: String,
 }
 
 #[derive(Resource)]
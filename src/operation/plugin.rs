@@ -1,4 +1,4 @@
-use crate::operation::{self, Operations};
+use crate::operation::{self, ImportError, Operations, SceneStats};
 use crate::render::RenderSystems;
 use bevy::prelude::*;
 
@@ -7,6 +7,8 @@ pub struct RapierOperationsPlugin;
 impl Plugin for RapierOperationsPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(Operations::default())
+            .init_resource::<SceneStats>()
+            .add_event::<ImportError>()
             .add_systems(Last, clear_operations)
             .add_systems(
                 Update,
@@ -16,6 +18,10 @@ impl Plugin for RapierOperationsPlugin {
                 Update,
                 operation::add_collision_shape.in_set(RenderSystems::ProcessCommands),
             )
+            .add_systems(
+                Update,
+                operation::add_heightfield.in_set(RenderSystems::ProcessCommands),
+            )
             .add_systems(
                 Update,
                 operation::add_intersection.in_set(RenderSystems::ProcessCommands),
@@ -33,11 +39,36 @@ impl Plugin for RapierOperationsPlugin {
             .add_systems(
                 Update,
                 operation::clear_scene.in_set(RenderSystems::ProcessCommands),
+            )
+            .add_systems(
+                Update,
+                operation::set_body_type.in_set(RenderSystems::ProcessCommands),
+            )
+            .add_systems(
+                Update,
+                operation::frame_selection.in_set(RenderSystems::ProcessCommands),
+            )
+            .add_systems(
+                Update,
+                operation::scene_stats.in_set(RenderSystems::ProcessCommands),
             );
         #[cfg(feature = "dim3")]
         {
-            app.add_systems(Update, operation::set_trimesh_flags)
-                .add_systems(Update, operation::import_mesh);
+            app.add_systems(
+                Update,
+                operation::import_mesh.in_set(RenderSystems::ProcessCommands),
+            )
+            .add_systems(Update, operation::finish_mesh_imports)
+            .add_systems(
+                Update,
+                operation::recompute_collider_shape.in_set(RenderSystems::ProcessCommands),
+            )
+            .add_systems(
+                Update,
+                operation::set_trimesh_flags
+                    .after(operation::recompute_collider_shape)
+                    .in_set(RenderSystems::ProcessCommands),
+            );
         }
     }
 }
@@ -0,0 +1,56 @@
+use crate::operation::{Operation, Operations};
+use crate::render::RenderInitialized;
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+pub fn recompute_collider_shape(
+    mut commands: Commands,
+    operations: Res<Operations>,
+    meshes: Res<Assets<Mesh>>,
+    mut colliders: Query<(&mut Collider, Option<&Handle<Mesh>>)>,
+) {
+    for op in operations.iter() {
+        if let Operation::RecomputeColliderShape { entity, shape } = op {
+            if let Ok((mut collider, mesh_handle)) = colliders.get_mut(*entity) {
+                if let Some(mesh) = mesh_handle.and_then(|handle| meshes.get(handle)) {
+                    // The rigid-body and transform are left untouched, only the shape is swapped.
+                    match recomputed_collider(mesh, shape) {
+                        Some(new_collider) => {
+                            *collider = new_collider;
+                            // Force the collider’s render mesh to be regenerated.
+                            commands.entity(*entity).remove::<RenderInitialized>();
+                        }
+                        None => error!("Failed to recompute the collider of entity {:?}.", entity),
+                    }
+                } else {
+                    error!(
+                        "Entity {:?} has no mesh to recompute its collider from.",
+                        entity
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Builds the collider of `mesh` with the given shape computation strategy.
+fn recomputed_collider(mesh: &Mesh, shape: &ComputedColliderShape) -> Option<Collider> {
+    Collider::from_bevy_mesh(mesh, shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parry::shape::ShapeType;
+
+    #[test]
+    fn recomputing_a_trimesh_as_a_convex_hull_changes_its_shape_type() {
+        let mesh = Mesh::from(bevy::math::primitives::Cuboid::new(1.0, 2.0, 3.0));
+
+        let trimesh = recomputed_collider(&mesh, &ComputedColliderShape::TriMesh).unwrap();
+        assert_eq!(trimesh.raw.shape_type(), ShapeType::TriMesh);
+
+        let hull = recomputed_collider(&mesh, &ComputedColliderShape::ConvexHull).unwrap();
+        assert_eq!(hull.raw.shape_type(), ShapeType::ConvexPolyhedron);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::operation::{Operation, Operations};
+use crate::parry::shape::ShapeType;
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+/// Summary of the scene’s content, updated by [`Operation::SceneStats`].
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SceneStats {
+    pub num_dynamic_bodies: usize,
+    pub num_fixed_bodies: usize,
+    pub num_kinematic_bodies: usize,
+    pub colliders_by_shape: Vec<(ShapeType, usize)>,
+    pub num_joints: usize,
+    pub num_trimesh_triangles: usize,
+}
+
+impl SceneStats {
+    pub fn num_bodies(&self) -> usize {
+        self.num_dynamic_bodies + self.num_fixed_bodies + self.num_kinematic_bodies
+    }
+
+    pub fn num_colliders(&self) -> usize {
+        self.colliders_by_shape.iter().map(|(_, n)| *n).sum()
+    }
+
+    fn add_collider(&mut self, shape_type: ShapeType) {
+        if let Some(entry) = self
+            .colliders_by_shape
+            .iter_mut()
+            .find(|e| e.0 == shape_type)
+        {
+            entry.1 += 1;
+        } else {
+            self.colliders_by_shape.push((shape_type, 1));
+        }
+    }
+}
+
+pub fn scene_stats(
+    operations: Res<Operations>,
+    mut stats: ResMut<SceneStats>,
+    bodies: Query<&RigidBody>,
+    colliders: Query<&Collider>,
+    impulse_joints: Query<(), With<ImpulseJoint>>,
+    multibody_joints: Query<(), With<MultibodyJoint>>,
+) {
+    for op in operations.iter() {
+        if let Operation::SceneStats = op {
+            let mut new_stats = SceneStats::default();
+
+            for body in bodies.iter() {
+                match body {
+                    RigidBody::Dynamic => new_stats.num_dynamic_bodies += 1,
+                    RigidBody::Fixed => new_stats.num_fixed_bodies += 1,
+                    RigidBody::KinematicPositionBased | RigidBody::KinematicVelocityBased => {
+                        new_stats.num_kinematic_bodies += 1
+                    }
+                }
+            }
+
+            for collider in colliders.iter() {
+                new_stats.add_collider(collider.raw.shape_type());
+
+                if let Some(trimesh) = collider.as_trimesh() {
+                    new_stats.num_trimesh_triangles += trimesh.raw.indices().len();
+                }
+            }
+
+            new_stats.num_joints = impulse_joints.iter().count() + multibody_joints.iter().count();
+            *stats = new_stats;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn scene_stats_counts_a_mixed_scene() {
+        let mut world = World::new();
+        world.init_resource::<SceneStats>();
+        let mut operations = Operations::new();
+        operations.push(Operation::SceneStats);
+        world.insert_resource(operations);
+
+        world.spawn((RigidBody::Dynamic, Collider::ball(0.5)));
+        world.spawn((RigidBody::Dynamic, Collider::ball(0.5)));
+        world.spawn((RigidBody::KinematicPositionBased, Collider::ball(0.5)));
+        world.spawn((
+            RigidBody::Fixed,
+            Collider::trimesh(
+                vec![Vect::ZERO, Vect::X, Vect::Y, Vect::X + Vect::Y],
+                vec![[0, 1, 2], [1, 3, 2]],
+            ),
+        ));
+
+        world.run_system_once(scene_stats);
+
+        let stats = world.resource::<SceneStats>();
+        assert_eq!(stats.num_dynamic_bodies, 2);
+        assert_eq!(stats.num_fixed_bodies, 1);
+        assert_eq!(stats.num_kinematic_bodies, 1);
+        assert_eq!(stats.num_bodies(), 4);
+        assert_eq!(stats.num_colliders(), 4);
+        assert_eq!(stats.num_trimesh_triangles, 2);
+        assert_eq!(stats.num_joints, 0);
+
+        let count = |shape_type| {
+            stats
+                .colliders_by_shape
+                .iter()
+                .find(|(ty, _)| *ty == shape_type)
+                .map(|(_, n)| *n)
+        };
+        assert_eq!(count(ShapeType::Ball), Some(3));
+        assert_eq!(count(ShapeType::TriMesh), Some(1));
+        assert_eq!(stats.colliders_by_shape.len(), 2);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::operation::{Operation, Operations};
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+pub fn set_body_type(
+    operations: Res<Operations>,
+    mut bodies: Query<(&mut RigidBody, Option<&mut Sleeping>)>,
+) {
+    for op in operations.iter() {
+        if let Operation::SetBodyType { entity, body_type } = op {
+            if let Ok((mut rigid_body, sleeping)) = bodies.get_mut(*entity) {
+                *rigid_body = *body_type;
+
+                // Wake the body up so it reacts to its new type right away.
+                if let Some(mut sleeping) = sleeping {
+                    sleeping.sleeping = false;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn set_body_type_flips_a_dynamic_body_to_fixed() {
+        let mut world = World::new();
+        let body = world
+            .spawn((
+                RigidBody::Dynamic,
+                Sleeping {
+                    sleeping: true,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        let mut operations = Operations::new();
+        operations.push(Operation::SetBodyType {
+            entity: body,
+            body_type: RigidBody::Fixed,
+        });
+        world.insert_resource(operations);
+
+        world.run_system_once(set_body_type);
+
+        assert_eq!(*world.get::<RigidBody>(body).unwrap(), RigidBody::Fixed);
+        assert!(!world.get::<Sleeping>(body).unwrap().sleeping);
+    }
+}
@@ -6,14 +6,15 @@ use bevy_rapier::rapier::math::{Point, Real, Vector};
 use na::{point, UnitQuaternion};
 
 use crate::cli::CliArgs;
-use crate::parry::shape::Cuboid;
+#[cfg(feature = "dim3")]
+use crate::parry::shape::{Compound, Cuboid, TypedShape};
 #[cfg(feature = "dim2")]
 use bevy::sprite::MaterialMesh2dBundle;
 use bevy_egui::egui::ahash::HashMap;
 
 #[derive(Resource, Default, Clone)]
 pub struct CollisionShapeMeshInstances {
-    cuboid_to_mesh: Vec<(Cuboid, Handle<Mesh>)>, // TODO: make this work for all collider types.
+    unit_cuboid_mesh: Option<Handle<Mesh>>, // TODO: make this work for all collider types.
     color_to_material: Vec<(Color, Handle<StandardMaterial>)>,
 }
 
@@ -54,7 +55,7 @@ pub fn create_collider_renders_system(
 ) {
     for (entity, collider, render, mut render_target) in coll_shape_render.iter_mut() {
         commands.entity(entity).insert(RenderInitialized); // FIXME: not sure what this is needed. Change detection for Changed<ColliderRender> should be enough.
-        if let Some(mesh) =
+        if let Some((mesh, mesh_scale)) =
             generate_collision_shape_render_mesh(collider, &mut *meshes, &mut instances)
         {
             // println!("Rendering with color: {:?}", render.color);
@@ -122,9 +123,11 @@ pub fn create_collider_renders_system(
                 if existing_entities.get(target).is_ok() {
                     let old_transform = old_transform.get(target).unwrap();
                     bundle.transform = *old_transform;
+                    bundle.transform.scale = mesh_scale;
                     commands.entity(target).insert(bundle);
                 }
             } else {
+                bundle.transform.scale = mesh_scale;
                 commands.entity(entity).with_children(|cmd| {
                     let target = cmd.spawn(bundle).id();
                     render_target.target = Some(target);
@@ -139,26 +142,20 @@ fn generate_collision_shape_render_mesh(
     collider: &Collider,
     meshes: &mut Assets<Mesh>,
     instances: &mut CollisionShapeMeshInstances,
-) -> Option<Handle<Mesh>> {
+) -> Option<(Handle<Mesh>, Vec3)> {
     const NSUB: u32 = 20;
 
     let ((vertices, indices), flat_normals) = match collider.as_unscaled_typed_shape() {
         ColliderView::Cuboid(s) => {
-            if let Some((_, mesh)) = instances
-                .cuboid_to_mesh
-                .iter()
-                .find(|(cuboid, _)| cuboid == s.raw)
-            {
-                return Some(mesh.clone());
-            }
-
-            let (vertices, indices) = s.raw.to_trimesh();
-            let mesh = gen_bevy_mesh(&vertices, &indices, true);
-            let handle = meshes.add(mesh);
-            instances
-                .cuboid_to_mesh
-                .push((s.raw.clone(), handle.clone()));
-            return Some(handle);
+            // All cuboids share the same unit cube mesh, scaled by the render target’s transform.
+            let handle = instances
+                .unit_cuboid_mesh
+                .get_or_insert_with(|| {
+                    let (vertices, indices) = Cuboid::new(Vector::repeat(0.5)).to_trimesh();
+                    meshes.add(gen_bevy_mesh(&vertices, &indices, true))
+                })
+                .clone();
+            return Some((handle, s.half_extents() * 2.0));
 
             // (s.raw.to_trimesh(), true)
         }
@@ -199,7 +196,7 @@ fn generate_collision_shape_render_mesh(
         }
         ColliderView::Capsule(s) => (s.raw.to_trimesh(NSUB, NSUB / 2), false),
         ColliderView::ConvexPolyhedron(s) => (s.raw.to_trimesh(), true),
-        // ColliderView::Compound(s) => s.raw.to_trimesh(),
+        ColliderView::Compound(s) => (compound_to_trimesh(s.raw), true),
         ColliderView::HeightField(s) => (s.raw.to_trimesh(), true),
         // ColliderView::Polyline(s) => s.raw.to_trimesh(),
         // ColliderView::Triangle(s) => s.raw.to_trimesh(),
@@ -224,7 +221,39 @@ fn generate_collision_shape_render_mesh(
     };
 
     let mesh = gen_bevy_mesh(&vertices, &indices, flat_normals);
-    Some(meshes.add(mesh))
+    Some((meshes.add(mesh), Vec3::ONE))
+}
+
+/// Merges the triangle meshes of all the compound’s sub-shapes, in the compound’s local frame.
+#[cfg(feature = "dim3")]
+fn compound_to_trimesh(compound: &Compound) -> (Vec<Point<Real>>, Vec<[u32; 3]>) {
+    const NSUB: u32 = 20;
+
+    let mut vertices = vec![];
+    let mut indices = vec![];
+
+    for (pos, shape) in compound.shapes() {
+        let (part_vertices, part_indices) = match shape.as_typed_shape() {
+            TypedShape::Cuboid(s) => s.to_trimesh(),
+            TypedShape::Ball(s) => s.to_trimesh(NSUB, NSUB / 2),
+            TypedShape::Capsule(s) => s.to_trimesh(NSUB, NSUB / 2),
+            TypedShape::Cylinder(s) => s.to_trimesh(NSUB),
+            TypedShape::Cone(s) => s.to_trimesh(NSUB),
+            TypedShape::ConvexPolyhedron(s) => s.to_trimesh(),
+            TypedShape::TriMesh(s) => (s.vertices().to_vec(), s.indices().to_vec()),
+            _ => continue,
+        };
+
+        let base_id = vertices.len() as u32;
+        vertices.extend(part_vertices.iter().map(|pt| pos * pt));
+        indices.extend(
+            part_indices
+                .iter()
+                .map(|idx| [idx[0] + base_id, idx[1] + base_id, idx[2] + base_id]),
+        );
+    }
+
+    (vertices, indices)
 }
 
 #[cfg(feature = "dim2")]
@@ -232,7 +261,7 @@ fn generate_collision_shape_render_mesh(
     collider: &Collider,
     meshes: &mut Assets<Mesh>,
     _unused: &mut CollisionShapeMeshInstances,
-) -> Option<Handle<Mesh>> {
+) -> Option<(Handle<Mesh>, Vec3)> {
     const NSUB: u32 = 20;
 
     let (vertices, indices) = match collider.as_unscaled_typed_shape() {
@@ -249,7 +278,7 @@ fn generate_collision_shape_render_mesh(
     };
 
     let mesh = gen_bevy_mesh(&vertices, indices);
-    Some(meshes.add(mesh))
+    Some((meshes.add(mesh), Vec3::ONE))
 }
 
 #[cfg(feature = "dim2")]
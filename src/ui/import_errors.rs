@@ -0,0 +1,37 @@
+use crate::operation::ImportError;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+/// Lists the import failures until the user closes the window.
+pub fn import_errors_ui(
+    mut ui_context: EguiContexts,
+    mut events: EventReader<ImportError>,
+    mut errors: Local<Vec<ImportError>>,
+) {
+    for event in events.read() {
+        error!(
+            "Failed to import {}: {}",
+            event.path.display(),
+            event.reason
+        );
+        errors.push(event.clone());
+    }
+
+    if errors.is_empty() {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("⚠ Import errors")
+        .open(&mut open)
+        .resizable(false)
+        .show(ui_context.ctx_mut(), |ui| {
+            for error in errors.iter() {
+                ui.label(format!("{}: {}", error.path.display(), error.reason));
+            }
+        });
+
+    if !open {
+        errors.clear();
+    }
+}
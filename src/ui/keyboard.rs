@@ -1,8 +1,10 @@
+use crate::operation::{Operation, Operations};
 use crate::selection::Selection;
 use bevy::prelude::*;
 
 pub fn handle_keyboard_inputs(
     mut commands: Commands,
+    mut operations: ResMut<Operations>,
     keys: Res<ButtonInput<KeyCode>>,
     selection: Query<(Entity, &Selection)>,
 ) {
@@ -13,4 +15,13 @@ pub fn handle_keyboard_inputs(
             }
         }
     }
+
+    if keys.just_released(KeyCode::KeyF) {
+        let entities = selection
+            .iter()
+            .filter(|(_, selection)| selection.selected())
+            .map(|(entity, _)| entity)
+            .collect();
+        operations.push(Operation::FrameSelection { entities });
+    }
 }
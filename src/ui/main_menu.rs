@@ -6,7 +6,8 @@ use bevy::app::AppExit;
 use bevy::prelude::*;
 use bevy::window::Window;
 use bevy_egui::{egui, EguiContexts};
-use bevy_rapier::plugin::{RapierConfiguration, RapierContext};
+use bevy_rapier::math::Vect;
+use bevy_rapier::plugin::{RapierConfiguration, RapierContext, TimestepMode};
 use bevy_rapier::render::DebugRenderContext;
 use std::path::PathBuf;
 
@@ -19,8 +20,8 @@ pub(super) fn ui(
     theme: &mut Theme,
     ui_context: &mut EguiContexts,
     ui_state: &mut UiState,
-    _physics_context: &mut RapierContext,
-    _physics_config: &mut RapierConfiguration,
+    physics_context: &mut RapierContext,
+    physics_config: &mut RapierConfiguration,
     debug_render_context: &mut DebugRenderContext,
     operations: &mut Operations,
     mut exit: EventWriter<AppExit>,
@@ -65,6 +66,14 @@ pub(super) fn ui(
                         debug_render::ui(ui, ui_state, &mut *debug_render_context);
                     });
 
+                    ui.menu_button("⏱ Time scale", |ui| {
+                        time_scale_ui(ui, physics_config);
+                    });
+
+                    ui.menu_button("⬇ Gravity", |ui| {
+                        gravity_ui(ui, physics_context, physics_config);
+                    });
+
                     ui.checkbox(&mut theme.dark_mode, "Dark mode");
 
                     if ui.button("ℹ Simulation infos…").clicked() {
@@ -83,6 +92,78 @@ pub(super) fn ui(
         });
 }
 
+fn time_scale_ui(ui: &mut egui::Ui, physics_config: &mut RapierConfiguration) {
+    match &mut physics_config.timestep_mode {
+        TimestepMode::Variable { time_scale, .. }
+        | TimestepMode::Interpolated { time_scale, .. } => {
+            ui.add(
+                // Don’t clamp, to keep a --time-scale set outside of the slider’s range.
+                egui::Slider::new(time_scale, 0.1..=4.0)
+                    .logarithmic(true)
+                    .clamp_to_range(false)
+                    .suffix("×"),
+            );
+            ui.horizontal(|ui| {
+                for preset in [0.25, 0.5, 1.0, 2.0] {
+                    if ui.button(format!("{}×", preset)).clicked() {
+                        *time_scale = preset;
+                    }
+                }
+            });
+        }
+        TimestepMode::Fixed { .. } => {
+            ui.label("Not available with a fixed timestep.");
+        }
+    }
+}
+
+fn gravity_ui(
+    ui: &mut egui::Ui,
+    physics_context: &mut RapierContext,
+    physics_config: &mut RapierConfiguration,
+) {
+    let mut gravity = physics_config.gravity;
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::DragValue::new(&mut gravity.x)
+                .speed(0.1)
+                .prefix("x: "),
+        );
+        ui.add(
+            egui::DragValue::new(&mut gravity.y)
+                .speed(0.1)
+                .prefix("y: "),
+        );
+        #[cfg(feature = "dim3")]
+        ui.add(
+            egui::DragValue::new(&mut gravity.z)
+                .speed(0.1)
+                .prefix("z: "),
+        );
+    });
+    ui.horizontal(|ui| {
+        if ui.button("Earth").clicked() {
+            gravity = Vect::Y * -9.81;
+        }
+        if ui.button("Zero").clicked() {
+            gravity = Vect::ZERO;
+        }
+        if ui.button("Flip").clicked() {
+            gravity = -gravity;
+        }
+    });
+
+    if gravity != physics_config.gravity {
+        physics_config.gravity = gravity;
+
+        // Sleeping bodies wouldn’t notice the new gravity otherwise.
+        for (_, body) in physics_context.bodies.iter_mut() {
+            body.wake_up(true);
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn import_data<T: for<'a> serde::Deserialize<'a>>() -> anyhow::Result<Option<T>> {
     if let Some(path) = FileDialog::new()
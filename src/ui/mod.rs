@@ -8,6 +8,7 @@ use bevy_egui::{
     EguiContexts,
 };
 use bevy_rapier::control::KinematicCharacterController;
+use bevy_rapier::geometry::Collider;
 use bevy_rapier::plugin::{RapierConfiguration, RapierContext};
 use bevy_rapier::render::DebugRenderContext;
 use strum_macros::EnumIter;
@@ -16,15 +17,17 @@ use ui_state::OpenObjectTab;
 pub use self::plugin::RapierUiPlugin;
 use crate::cli::CliArgs;
 use crate::control::CharacterControlOptions;
-use crate::operation::Operations;
+use crate::operation::{Operations, SceneStats};
 use crate::styling::Theme;
 pub(self) use gizmo::add_missing_gizmos;
+pub(self) use import_errors::import_errors_ui;
 pub(self) use input_blocking::focus_ui;
 pub(self) use keyboard::handle_keyboard_inputs;
 pub use ui_state::{ActiveMouseAction, SelectedTool, UiState};
 
 mod debug_render;
 mod gizmo;
+mod import_errors;
 mod input_blocking;
 mod keyboard;
 mod main_menu;
@@ -160,7 +163,12 @@ pub fn load_assets(
 
 pub fn update_ui(
     mut commands: Commands,
-    (cli, mut theme): (Res<CliArgs>, ResMut<Theme>),
+    (cli, mut theme, scene_stats, mesh_colliders): (
+        Res<CliArgs>,
+        ResMut<Theme>,
+        Res<SceneStats>,
+        Query<(), (With<Collider>, With<Handle<Mesh>>)>,
+    ),
     mut ui_context: EguiContexts,
     mut ui_state: ResMut<UiState>,
     mut debug_render_context: ResMut<DebugRenderContext>,
@@ -213,7 +221,13 @@ pub fn update_ui(
             &mut *physics_config,
             &mut *operations,
         );
-        simulation_infos::ui(&mut ui_context, &mut ui_state, &*physics_context);
+        simulation_infos::ui(
+            &mut ui_context,
+            &mut ui_state,
+            &*physics_context,
+            &*scene_stats,
+            &mut *operations,
+        );
         right_panel::ui(
             &mut commands,
             window,
@@ -222,12 +236,14 @@ pub fn update_ui(
             &mut ui_state,
             &mut *physics_context,
             &mut *physics_config,
+            &mut *operations,
             &mut bodies,
             &mut colliders,
             &mut character_controllers,
             &mut selections,
             &mut visibility,
             &mut transforms,
+            &mesh_colliders,
         );
     }
 }
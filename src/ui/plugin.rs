@@ -1,4 +1,5 @@
 use super::{ActiveMouseAction, UiState};
+use crate::render::RenderSystems;
 use bevy::prelude::*;
 
 /// Plugin responsible for creating an UI for interacting, monitoring, and modifying the simulation.
@@ -12,7 +13,14 @@ impl Plugin for RapierUiPlugin {
             .add_systems(Startup, super::load_assets)
             .add_systems(PreUpdate, super::focus_ui)
             .add_systems(Update, super::add_missing_gizmos)
-            .add_systems(Update, super::update_ui)
-            .add_systems(Update, super::handle_keyboard_inputs);
+            .add_systems(
+                Update,
+                super::update_ui.in_set(RenderSystems::BeforeCommands),
+            )
+            .add_systems(Update, super::import_errors_ui)
+            .add_systems(
+                Update,
+                super::handle_keyboard_inputs.in_set(RenderSystems::BeforeCommands),
+            );
     }
 }
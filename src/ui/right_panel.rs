@@ -1,5 +1,7 @@
 use crate::cli::CliArgs;
 use crate::control::CharacterControlOptions;
+use crate::one_way_platform::OneWayPlatform;
+use crate::operation::{Operation, Operations};
 use crate::selection::Selection;
 use crate::utils::{ColliderComponentsMut, RigidBodyComponentsMut};
 use bevy::prelude::*;
@@ -17,6 +19,7 @@ pub(super) fn ui(
     ui_state: &mut UiState,
     _physics_context: &mut RapierContext,
     _physics_config: &mut RapierConfiguration,
+    operations: &mut Operations,
     bodies: &mut Query<RigidBodyComponentsMut>,
     colliders: &mut Query<ColliderComponentsMut>,
     character_controllers: &mut Query<(
@@ -26,6 +29,7 @@ pub(super) fn ui(
     selections: &mut Query<(Entity, &mut Selection)>,
     visibility: &mut Query<(Entity, &mut Visibility)>,
     transforms: &mut Query<(Entity, &mut Transform)>,
+    mesh_colliders: &Query<(), (With<Collider>, With<Handle<Mesh>>)>,
 ) {
     if cli.lower_graphics {
         return;
@@ -57,11 +61,14 @@ pub(super) fn ui(
                 selection_inspector(
                     commands,
                     ui,
+                    cli,
+                    operations,
                     bodies,
                     colliders,
                     character_controllers,
                     selections,
                     transforms,
+                    mesh_colliders,
                 );
             }
         });
@@ -120,9 +127,12 @@ fn scene_explorer(
         });
 }
 
+#[cfg_attr(feature = "dim2", allow(unused_variables))]
 fn selection_inspector(
     commands: &mut Commands,
     ui: &mut egui::Ui,
+    cli: &CliArgs,
+    operations: &mut Operations,
     bodies: &mut Query<RigidBodyComponentsMut>,
     colliders: &mut Query<ColliderComponentsMut>,
     character_controllers: &mut Query<(
@@ -131,6 +141,7 @@ fn selection_inspector(
     )>,
     selections: &mut Query<(Entity, &mut Selection)>,
     transforms: &mut Query<(Entity, &mut Transform)>,
+    mesh_colliders: &Query<(), (With<Collider>, With<Handle<Mesh>>)>,
 ) {
     let mut selected_any = false;
     for (entity, selected) in selections.iter() {
@@ -139,7 +150,7 @@ fn selection_inspector(
 
             if let Ok((
                 _entity,
-                mut rb,
+                rb,
                 mut vel,
                 _mprops,
                 mut locked_axes,
@@ -150,26 +161,32 @@ fn selection_inspector(
                 mut sleep_state,
                 disabled,
                 read_mass_props,
+                mut damping,
             )) = bodies.get_mut(entity)
             {
                 ui.horizontal(|ui| {
+                    let mut body_type = *rb;
                     ui.label("Rigid-body type: ");
                     egui::ComboBox::from_id_source("Rigid-body type")
-                        .selected_text(format!("{:?}", *rb))
+                        .selected_text(format!("{:?}", body_type))
                         .show_ui(ui, |ui| {
-                            ui.selectable_value(&mut *rb, RigidBody::Dynamic, "Dynamic");
-                            ui.selectable_value(&mut *rb, RigidBody::Fixed, "Fixed");
+                            ui.selectable_value(&mut body_type, RigidBody::Dynamic, "Dynamic");
+                            ui.selectable_value(&mut body_type, RigidBody::Fixed, "Fixed");
                             ui.selectable_value(
-                                &mut *rb,
+                                &mut body_type,
                                 RigidBody::KinematicPositionBased,
                                 "KinematicPositionBased",
                             );
                             ui.selectable_value(
-                                &mut *rb,
+                                &mut body_type,
                                 RigidBody::KinematicVelocityBased,
                                 "KinematicVelocityBased",
                             );
                         });
+
+                    if body_type != *rb {
+                        operations.push(Operation::SetBodyType { entity, body_type });
+                    }
                 });
 
                 egui::Grid::new("Rigid-body props").show(ui, |ui| {
@@ -362,10 +379,13 @@ fn selection_inspector(
                         ui.label("Can sleep: ");
                         if ui.checkbox(&mut can_sleep, "").changed() {
                             if can_sleep {
-                                **sleep_state = Sleeping {
+                                let mut new_state = Sleeping {
                                     sleeping,
                                     ..Default::default()
-                                }
+                                };
+                                // Keep the thresholds set on the command line.
+                                cli.override_sleep_thresholds(&mut new_state);
+                                **sleep_state = new_state;
                             } else {
                                 **sleep_state = Sleeping {
                                     sleeping,
@@ -377,6 +397,30 @@ fn selection_inspector(
                         ui.end_row();
                     }
 
+                    if let Some(damping) = damping.as_mut() {
+                        let mut new_damping = **damping;
+                        ui.label("Damping: ");
+                        ui.horizontal(|ui| {
+                            ui.add(
+                                egui::DragValue::new(&mut new_damping.linear_damping)
+                                    .speed(0.01)
+                                    .clamp_range(0.0..=f32::MAX)
+                                    .prefix("lin: "),
+                            );
+                            ui.add(
+                                egui::DragValue::new(&mut new_damping.angular_damping)
+                                    .speed(0.01)
+                                    .clamp_range(0.0..=f32::MAX)
+                                    .prefix("ang: "),
+                            );
+                        });
+
+                        if new_damping != **damping {
+                            **damping = new_damping;
+                        }
+                        ui.end_row();
+                    }
+
                     if let Some(read_mass_props) = read_mass_props {
                         let mut mprops = *read_mass_props;
                         ui.label("Mass");
@@ -395,10 +439,30 @@ fn selection_inspector(
                 }
             }
 
-            if let Ok((_entity, _collider, _sensor, _mprops, mut coll_groups, _disabled)) =
+            if let Ok((_entity, _collider, _sensor, _mprops, mut coll_groups, disabled, one_way)) =
                 colliders.get_mut(entity)
             {
                 egui::Grid::new("Collider props").show(ui, |ui| {
+                    let mut enabled = disabled.is_none();
+                    if ui.checkbox(&mut enabled, "Collider enabled").changed() {
+                        if enabled {
+                            commands.entity(entity).remove::<ColliderDisabled>();
+                        } else {
+                            commands.entity(entity).insert(ColliderDisabled);
+                        }
+                    }
+                    ui.end_row();
+
+                    let mut is_one_way = one_way.is_some();
+                    if ui.checkbox(&mut is_one_way, "One-way platform").changed() {
+                        if is_one_way {
+                            commands.entity(entity).insert(OneWayPlatform);
+                        } else {
+                            commands.entity(entity).remove::<OneWayPlatform>();
+                        }
+                    }
+                    ui.end_row();
+
                     if let Some(coll_groups) = &mut coll_groups {
                         const BITS: usize = 4;
                         let mut gbits = [false; BITS];
@@ -451,6 +515,28 @@ fn selection_inspector(
                         }
                     }
                 });
+
+                #[cfg(feature = "dim3")]
+                if mesh_colliders.contains(entity) {
+                    ui.horizontal(|ui| {
+                        ui.label("Recompute shape: ");
+                        let shapes = [
+                            ("Trimesh", ComputedColliderShape::TriMesh),
+                            ("Convex hull", ComputedColliderShape::ConvexHull),
+                            (
+                                "Convex decomposition",
+                                ComputedColliderShape::ConvexDecomposition(Default::default()),
+                            ),
+                        ];
+
+                        for (label, shape) in shapes {
+                            if ui.button(label).clicked() {
+                                operations
+                                    .push(Operation::RecomputeColliderShape { entity, shape });
+                            }
+                        }
+                    });
+                }
             }
 
             ui.separator();
@@ -1,9 +1,16 @@
+use crate::operation::{Operation, Operations, SceneStats};
 use crate::ui::UiState;
 use bevy_egui::{egui, EguiContexts};
 use bevy_rapier::plugin::RapierContext;
 use bevy_rapier::rapier::counters::Counters;
 
-pub(super) fn ui(ui_context: &mut EguiContexts, ui_state: &mut UiState, physics: &RapierContext) {
+pub(super) fn ui(
+    ui_context: &mut EguiContexts,
+    ui_state: &mut UiState,
+    physics: &RapierContext,
+    scene_stats: &SceneStats,
+    operations: &mut Operations,
+) {
     egui::Window::new("ℹ Simulation infos")
         .open(&mut ui_state.simulation_infos_open)
         .resizable(false)
@@ -12,6 +19,12 @@ pub(super) fn ui(ui_context: &mut EguiContexts, ui_state: &mut UiState, physics:
             ui.collapsing("Profile infos", |ui| {
                 ui.horizontal_wrapped(|ui| ui.label(profiling_string(&physics.pipeline.counters)));
             });
+            ui.collapsing("Scene stats", |ui| {
+                if ui.button("Refresh").clicked() {
+                    operations.push(Operation::SceneStats);
+                }
+                ui.label(scene_stats_string(scene_stats));
+            });
             // ui.collapsing("Serialization infos", |ui| {
             //     ui.horizontal_wrapped(|ui| ui.label(serialization_string(0, physics)));
             // });
@@ -22,13 +35,52 @@ fn stats_string(physics: &RapierContext) -> String {
     format!(
         r#"Rigid-bodies: {}
 Colliders: {}
-Impulse joints: {}"#,
+Impulse joints: {}
+Kinetic energy: {:.3}J"#,
         physics.bodies.len(),
         physics.colliders.len(),
         physics.impulse_joints.len(),
+        kinetic_energy(physics),
     )
 }
 
+/// Total kinetic energy of the dynamic bodies, close to zero once the scene is at rest.
+fn kinetic_energy(physics: &RapierContext) -> f32 {
+    physics
+        .bodies
+        .iter()
+        .filter(|(_, rb)| rb.is_dynamic())
+        .map(|(_, rb)| rb.kinetic_energy())
+        .sum()
+}
+
+fn scene_stats_string(stats: &SceneStats) -> String {
+    let mut result = format!(
+        r#"Rigid-bodies: {}
+        Dynamic: {}
+        Fixed: {}
+        Kinematic: {}
+Colliders: {}"#,
+        stats.num_bodies(),
+        stats.num_dynamic_bodies,
+        stats.num_fixed_bodies,
+        stats.num_kinematic_bodies,
+        stats.num_colliders(),
+    );
+
+    for (shape_type, count) in &stats.colliders_by_shape {
+        result += &format!("\n        {:?}: {}", shape_type, count);
+    }
+
+    result += &format!(
+        r#"
+Joints: {}
+Trimesh triangles: {}"#,
+        stats.num_joints, stats.num_trimesh_triangles,
+    );
+    result
+}
+
 fn profiling_string(counters: &Counters) -> String {
     format!(
         r#"Total: {:.2}ms
@@ -14,7 +14,11 @@ use {
 };
 
 #[cfg(feature = "dim3")]
-use bevy_rapier::geometry::ComputedColliderShape;
+use {
+    crate::operation::{MeshImportOptions, UpAxis},
+    crate::parry::shape::TriMeshFlags,
+    bevy_rapier::geometry::ComputedColliderShape,
+};
 
 pub(super) fn ui(
     window: &Window,
@@ -138,10 +142,18 @@ pub(super) fn ui(
                         .add_filter("OBJ Mesh", &["obj"])
                         .show_open_single_file()
                     {
-                        operations.push(Operation::ImportMesh(path, ComputedColliderShape::TriMesh))
+                        operations.push(Operation::ImportMesh(
+                            path,
+                            ComputedColliderShape::TriMesh,
+                            ui_state.mesh_import_options,
+                        ))
                     }
                 }
 
+                ui.menu_button("⚙", |ui| {
+                    mesh_import_options_ui(ui, &mut ui_state.mesh_import_options)
+                });
+
                 #[cfg(feature = "voxels")]
                 if ui
                     .add(egui::Button::new(ButtonTexture::ImportVoxels.rich_text()))
@@ -182,6 +194,7 @@ pub(super) fn ui(
                                     voxel_size: 0.1,
                                     fill_mode: crate::FillMode::SurfaceOnly, // FillMode::default(),
                                 },
+                                ui_state.mesh_import_options,
                             ))
                         }
                     }
@@ -217,3 +230,57 @@ pub(super) fn ui(
             });
         });
 }
+
+#[cfg(feature = "dim3")]
+#[cfg(not(target_arch = "wasm32"))]
+fn mesh_import_options_ui(ui: &mut egui::Ui, options: &mut MeshImportOptions) {
+    ui.label("Triangle mesh flags");
+    let flags = [
+        ("Fix internal edges", TriMeshFlags::FIX_INTERNAL_EDGES),
+        ("Oriented", TriMeshFlags::ORIENTED),
+        (
+            "Merge duplicate vertices",
+            TriMeshFlags::MERGE_DUPLICATE_VERTICES,
+        ),
+        (
+            "Delete degenerate triangles",
+            TriMeshFlags::DELETE_DEGENERATE_TRIANGLES,
+        ),
+        (
+            "Delete duplicate triangles",
+            TriMeshFlags::DELETE_DUPLICATE_TRIANGLES,
+        ),
+    ];
+
+    for (label, flag) in flags {
+        let mut enabled = options.trimesh_flags.contains(flag);
+        if ui.checkbox(&mut enabled, label).changed() {
+            options.trimesh_flags.set(flag, enabled);
+        }
+    }
+
+    if ui.button("Level floor preset").clicked() {
+        options.trimesh_flags = MeshImportOptions::LEVEL_FLOOR_FLAGS;
+    }
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label("Up axis");
+        ui.selectable_value(&mut options.up_axis, UpAxis::Y, "Y");
+        ui.selectable_value(&mut options.up_axis, UpAxis::Z, "Z");
+    });
+    ui.horizontal(|ui| {
+        ui.label("Scale");
+        ui.add(
+            egui::DragValue::new(&mut options.scale)
+                .speed(0.01)
+                .clamp_range(1.0e-4..=1.0e4),
+        );
+        if ui.button("cm").clicked() {
+            options.scale = 0.01;
+        }
+        if ui.button("m").clicked() {
+            options.scale = 1.0;
+        }
+    });
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "dim3")]
+use crate::operation::MeshImportOptions;
 use bevy::prelude::*;
 use bevy_egui::egui::{Color32, FontId, RichText, TextureId};
 
@@ -22,6 +24,8 @@ pub struct UiState {
     pub single_step: bool,
     pub running: bool,
     pub interpolation: bool,
+    #[cfg(feature = "dim3")]
+    pub mesh_import_options: MeshImportOptions,
 }
 
 impl Default for UiState {
@@ -36,6 +40,8 @@ impl Default for UiState {
             single_step: false,
             running: false,
             interpolation: true,
+            #[cfg(feature = "dim3")]
+            mesh_import_options: MeshImportOptions::default(),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::one_way_platform::OneWayPlatform;
 use crate::render::{ColliderOutlineRender, ColliderRender};
 use crate::styling::ColorGenerator;
 use bevy::prelude::*;
@@ -18,6 +19,7 @@ pub type RigidBodyComponentsMut<'a> = (
     Option<&'a mut Sleeping>,
     Option<&'a RigidBodyDisabled>,
     Option<&'a ReadMassProperties>,
+    Option<&'a mut Damping>,
 );
 
 pub type ColliderComponentsMut<'a> = (
@@ -27,6 +29,7 @@ pub type ColliderComponentsMut<'a> = (
     Option<&'a mut ColliderMassProperties>,
     Option<&'a mut CollisionGroups>,
     Option<&'a ColliderDisabled>,
+    Option<&'a OneWayPlatform>,
 );
 
 #[derive(Clone, Bundle, Default)]
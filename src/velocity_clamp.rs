@@ -0,0 +1,81 @@
+use crate::cli::CliArgs;
+use bevy::prelude::*;
+use bevy_rapier::prelude::*;
+
+/// Plugin responsible for capping the velocities of the bodies moving abnormally fast.
+pub struct VelocityClampPlugin;
+
+impl Plugin for VelocityClampPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, clamp_velocities.after(PhysicsSet::Writeback));
+    }
+}
+
+fn clamp_velocities(cli: Res<CliArgs>, mut bodies: Query<(Entity, &mut Velocity)>) {
+    if cli.max_linvel.is_none() && cli.max_angvel.is_none() {
+        return;
+    }
+
+    for (entity, mut vel) in bodies.iter_mut() {
+        // Check on a copy first to avoid triggering bevy’s change detection.
+        let mut clamped = *vel;
+        if clamp_velocity(&mut clamped, cli.max_linvel, cli.max_angvel) {
+            // This can fire every frame for a body stuck above the limit, so keep it quiet.
+            debug!("Clamped the velocity of {:?}.", entity);
+            *vel = clamped;
+        }
+    }
+}
+
+/// Scales down the velocities above the given maximums, and returns whether any was clamped.
+fn clamp_velocity(vel: &mut Velocity, max_linvel: Option<f32>, max_angvel: Option<f32>) -> bool {
+    let mut clamped = false;
+
+    if let Some(max_linvel) = max_linvel {
+        let linvel = vel.linvel.length();
+        if linvel > max_linvel && linvel > 0.0 {
+            vel.linvel *= max_linvel / linvel;
+            clamped = true;
+        }
+    }
+
+    if let Some(max_angvel) = max_angvel {
+        #[cfg(feature = "dim2")]
+        let angvel = vel.angvel.abs();
+        #[cfg(feature = "dim3")]
+        let angvel = vel.angvel.length();
+
+        if angvel > max_angvel && angvel > 0.0 {
+            vel.angvel *= max_angvel / angvel;
+            clamped = true;
+        }
+    }
+
+    clamped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enormous_velocity_is_clamped_to_the_maximum() {
+        let mut vel = Velocity::linear(Vect::X * 1.0e6);
+        assert!(clamp_velocity(&mut vel, Some(100.0), None));
+        assert!((vel.linvel.length() - 100.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn velocity_below_the_maximum_is_untouched() {
+        let mut vel = Velocity::linear(Vect::X * 10.0);
+        assert!(!clamp_velocity(&mut vel, Some(100.0), Some(100.0)));
+        assert_eq!(vel.linvel, Vect::X * 10.0);
+    }
+
+    #[test]
+    fn zero_velocity_with_a_zero_maximum_stays_zero() {
+        let mut vel = Velocity::zero();
+        assert!(!clamp_velocity(&mut vel, Some(0.0), Some(0.0)));
+        assert_eq!(vel.linvel, Vect::ZERO);
+    }
+}